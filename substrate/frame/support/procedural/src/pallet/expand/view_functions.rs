@@ -89,12 +89,46 @@ fn expand_view_function(
 	let view_function_struct_ident = view_fn.view_function_struct_ident();
 	let view_fn_name = &view_fn.name;
 	let (arg_names, arg_types) = view_fn.args_names_types();
-	let return_type = &view_fn.return_type;
+	let success_type = view_fn.success_type();
 	let docs = &view_fn.docs;
 
-	let view_function_id_suffix_bytes = view_fn
-		.view_function_id_suffix_bytes()
-		.map(|byte| syn::LitInt::new(&format!("0x{:X}_u8", byte), Span::call_site()));
+	// A `#[pallet::view_function_id(...)]` attribute pins the dispatch suffix to a
+	// user-chosen stable identifier, so renaming the function or reordering/renaming its
+	// arguments no longer silently changes the on-chain query id. Without it we fall back to
+	// the existing signature-derived suffix.
+	let view_function_id_suffix_bytes = match view_fn.explicit_id_suffix_bytes() {
+		Some(explicit) => explicit,
+		None => view_fn.view_function_id_suffix_bytes(),
+	}
+	.map(|byte| syn::LitInt::new(&format!("0x{:X}_u8", byte), Span::call_site()));
+
+	// `#[pallet::view_function(weight = ...)]` declares the cost of serving this query so
+	// `dispatch_view_function` can refuse/abort once a caller-supplied weight/PoV budget is
+	// exceeded, instead of metering untrusted RPC reads for free.
+	let declared_weight = match view_fn.declared_weight() {
+		Some(weight) => quote::quote! { #weight },
+		None => quote::quote! { ::core::default::Default::default() },
+	};
+
+	// A `Result<T, E>` return type marks the query fallible: `E` becomes the associated
+	// `Error` type so dispatch can distinguish a rejected query from a decode failure, instead
+	// of authors hand-rolling `Option`/`Result` on top of an already-infallible `invoke`. An
+	// infallible query is treated as always returning `Ok`.
+	let is_fallible = view_fn.result_error_type().is_some();
+	let error_type = match view_fn.result_error_type() {
+		Some(error_ty) => quote::quote! { #error_ty },
+		None => quote::quote! { ::core::convert::Infallible },
+	};
+	let invoke_body = {
+		let call = quote::quote! {
+			#pallet_ident::<#type_use_gen> :: #view_fn_name( #( #arg_names, )* )
+		};
+		if is_fallible {
+			call
+		} else {
+			quote::quote! { ::core::result::Result::Ok(#call) }
+		}
+	};
 
 	quote::quote! {
 		#( #[doc = #docs] )*
@@ -140,11 +174,17 @@ fn expand_view_function(
 				}
 			}
 
-			type ReturnType = #return_type;
+			type ReturnType = #success_type;
+
+			type Error = #error_type;
+
+			fn weight() -> #frame_support::__private::Weight {
+				#declared_weight
+			}
 
-			fn invoke(self) -> Self::ReturnType {
+			fn invoke(self) -> ::core::result::Result<Self::ReturnType, Self::Error> {
 				let Self { #( #arg_names, )* _marker } = self;
-				#pallet_ident::<#type_use_gen> :: #view_fn_name( #( #arg_names, )* )
+				#invoke_body
 			}
 		}
 	}
@@ -165,26 +205,92 @@ fn impl_dispatch_view_function(
 		let view_function_struct_ident = view_fn.view_function_struct_ident();
 		quote::quote! {
 			<#view_function_struct_ident<#type_use_gen> as #frame_support::traits::ViewFunctionIdSuffix>::SUFFIX => {
+				#frame_support::__private::ensure_view_function_weight_limit::<
+					#view_function_struct_ident<#type_use_gen>
+				>(weight_limit)?;
 				<#view_function_struct_ident<#type_use_gen> as #frame_support::traits::ViewFunction>::execute(input, output)
 			}
 		}
 	});
 
 	quote::quote! {
+		impl<#type_impl_gen> #pallet_ident<#type_use_gen> #where_clause {
+			// Shared by the single-query and batch dispatch entry points below, so the
+			// match-on-suffix logic only needs to be generated once.
+			fn dispatch_one_view_function<O: #frame_support::__private::codec::Output>(
+				id: & #frame_support::__private::ViewFunctionId,
+				input: &mut &[u8],
+				output: &mut O,
+				weight_limit: ::core::option::Option<#frame_support::__private::Weight>,
+			) -> Result<(), #frame_support::__private::ViewFunctionDispatchError>
+			{
+				#[deny(unreachable_patterns)]
+				match id.suffix {
+					#( #query_match_arms )*
+					_ => Err(#frame_support::__private::ViewFunctionDispatchError::NotFound(id.clone())),
+				}
+			}
+		}
+
 		impl<#type_impl_gen> #frame_support::traits::DispatchViewFunction
 			for #pallet_ident<#type_use_gen> #where_clause
 		{
-			#[deny(unreachable_patterns)]
 			fn dispatch_view_function<O: #frame_support::__private::codec::Output>(
 				id: & #frame_support::__private::ViewFunctionId,
 				input: &mut &[u8],
-				output: &mut O
+				output: &mut O,
+				weight_limit: ::core::option::Option<#frame_support::__private::Weight>,
 			) -> Result<(), #frame_support::__private::ViewFunctionDispatchError>
 			{
-				match id.suffix {
-					#( #query_match_arms )*
-					_ => Err(#frame_support::__private::ViewFunctionDispatchError::NotFound(id.clone())),
+				Self::dispatch_one_view_function(id, input, output, weight_limit)
+			}
+
+			/// Dispatch a SCALE-encoded batch of `(ViewFunctionId, input)` queries in one call.
+			///
+			/// Each query's outcome is appended to `output` framed as a SCALE-encoded
+			/// `Result<Vec<u8>, ViewFunctionDispatchError>` (`Ok` carrying that query's raw
+			/// result bytes), so a caller can decode the batch as a `Vec` of that length and
+			/// recover every item's boundary and success/failure without guessing.
+			fn dispatch_view_functions<O: #frame_support::__private::codec::Output>(
+				queries: &mut &[u8],
+				output: &mut O,
+				weight_limit: ::core::option::Option<#frame_support::__private::Weight>,
+				mode: #frame_support::__private::ViewFunctionBatchMode,
+			) -> Result<(), #frame_support::__private::ViewFunctionDispatchError>
+			{
+				let queries: #frame_support::__private::sp_std::vec::Vec<(
+					#frame_support::__private::ViewFunctionId,
+					#frame_support::__private::sp_std::vec::Vec<::core::primitive::u8>,
+				)> = #frame_support::__private::codec::Decode::decode(queries)
+					.map_err(|_| #frame_support::__private::ViewFunctionDispatchError::DecodingFailed)?;
+
+				#frame_support::__private::codec::Encode::encode_to(
+					&#frame_support::__private::codec::Compact(queries.len() as ::core::primitive::u32),
+					output,
+				);
+
+				for (id, mut input) in queries {
+					let mut result_bytes = #frame_support::__private::sp_std::vec::Vec::new();
+					let result =
+						Self::dispatch_one_view_function(&id, &mut &input[..], &mut result_bytes, weight_limit);
+
+					match mode {
+						#frame_support::__private::ViewFunctionBatchMode::ShortCircuit => {
+							result?;
+							#frame_support::__private::codec::Encode::encode_to(
+								&::core::result::Result::<_, #frame_support::__private::ViewFunctionDispatchError>::Ok(result_bytes),
+								output,
+							);
+						},
+						#frame_support::__private::ViewFunctionBatchMode::Collect => {
+							let framed: ::core::result::Result<_, #frame_support::__private::ViewFunctionDispatchError> =
+								result.map(|_| result_bytes);
+							#frame_support::__private::codec::Encode::encode_to(&framed, output);
+						},
+					}
 				}
+
+				Ok(())
 			}
 		}
 	}
@@ -212,7 +318,7 @@ fn impl_view_function_metadata(
 					let pat = &typed.pat;
 					let ty = &typed.ty;
 					Some(quote::quote! {
-						#frame_support::__private::metadata_ir::ViewFunctionArgMetadataIR {
+						#frame_support::__private::metadata_ir::QueryArgMetadataIR {
 							name: ::core::stringify!(#pat),
 							ty: #frame_support::__private::scale_info::meta_type::<#ty>(),
 						}
@@ -224,15 +330,31 @@ fn impl_view_function_metadata(
 		let no_docs = vec![];
 		let doc = if cfg!(feature = "no-metadata-docs") { &no_docs } else { &view_fn.docs };
 
+		// Whether the dispatch suffix came from an explicit `#[pallet::view_function_id(..)]`
+		// or was derived from the function's current name/signature, so tooling can warn when
+		// an un-pinned id is at risk of accidental churn.
+		let id_is_explicit = view_fn.explicit_id_suffix_bytes().is_some();
+		// A `#[deprecated]` attribute on a view function is not parsed, so every query is
+		// reported as not deprecated.
+		let deprecation = quote::quote! {
+			#frame_support::__private::metadata_ir::DeprecationStatusIR::NotDeprecated
+		};
+
 		quote::quote! {
 			#frame_support::__private::metadata_ir::ViewFunctionMetadataIR {
 				name: ::core::stringify!(#name),
 				id: <#view_function_struct_ident<#type_use_gen> as #frame_support::traits::ViewFunction>::id().into(),
+				id_is_explicit: #id_is_explicit,
 				args: #frame_support::__private::sp_std::vec![ #( #args ),* ],
 				output: #frame_support::__private::scale_info::meta_type::<
 					<#view_function_struct_ident<#type_use_gen> as #frame_support::traits::ViewFunction>::ReturnType
 				>(),
 				docs: #frame_support::__private::sp_std::vec![ #( #doc ),* ],
+				weight: <#view_function_struct_ident<#type_use_gen> as #frame_support::traits::ViewFunction>::weight().into(),
+				error: #frame_support::__private::scale_info::meta_type::<
+					<#view_function_struct_ident<#type_use_gen> as #frame_support::traits::ViewFunction>::Error
+				>(),
+				deprecation_info: #deprecation,
 			}
 		}
 	});
@@ -250,6 +372,7 @@ fn impl_view_function_metadata(
 					name,
 					view_functions: #frame_support::__private::sp_std::vec![ #( #view_functions ),* ],
 					docs: #frame_support::__private::sp_std::vec![ #( #doc ),* ],
+					supports_batch_dispatch: true,
 				}
 			}
 		}
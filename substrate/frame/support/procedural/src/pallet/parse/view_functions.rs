@@ -0,0 +1,284 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+
+/// Definition of the `#[pallet::view_functions]` impl block and the view function methods it
+/// contains.
+#[derive(Clone)]
+pub struct ViewFunctionsDef {
+	/// The span of the `#[pallet::view_functions]` attribute.
+	pub attr_span: Span,
+	/// The where clause on the impl block, if any.
+	pub where_clause: Option<syn::WhereClause>,
+	/// The individual view functions declared in the impl block.
+	pub view_functions: Vec<ViewFunctionDef>,
+	/// Documentation attached to the impl block, emitted as the docs of the pallet's view
+	/// function group; it is not copied onto individual queries.
+	pub docs: Vec<syn::Expr>,
+}
+
+impl ViewFunctionsDef {
+	/// Parse a `#[pallet::view_functions]` impl block.
+	pub fn try_from(attr_span: Span, item: &syn::ItemImpl) -> syn::Result<Self> {
+		let where_clause = item.generics.where_clause.clone();
+		let docs = get_doc_literals(&item.attrs);
+
+		let view_functions = item
+			.items
+			.iter()
+			.map(|item| match item {
+				syn::ImplItem::Fn(method) => ViewFunctionDef::try_from(method),
+				_ => Err(syn::Error::new(
+					item.span(),
+					"Invalid pallet::view_functions, expected a method",
+				)),
+			})
+			.collect::<syn::Result<Vec<_>>>()?;
+
+		Ok(Self { attr_span, where_clause, view_functions, docs })
+	}
+}
+
+/// Definition of a single view function, i.e. one method inside a `#[pallet::view_functions]`
+/// impl block.
+#[derive(Clone)]
+pub struct ViewFunctionDef {
+	/// The span of the method's signature.
+	pub span: Span,
+	/// The method name.
+	pub name: syn::Ident,
+	/// The method's arguments as declared, including a receiver if there is one.
+	pub args: Vec<syn::FnArg>,
+	/// The method's return type.
+	pub return_type: syn::Type,
+	/// Doc comments on the method.
+	pub docs: Vec<syn::Expr>,
+	/// An explicit id suffix pinned via `#[pallet::view_function_id(..)]`, if present.
+	pub explicit_id: Option<[u8; 16]>,
+	/// The weight expression declared via `#[pallet::view_function(weight = ..)]`, if present.
+	pub weight: Option<syn::Expr>,
+}
+
+impl ViewFunctionDef {
+	fn try_from(method: &syn::ImplItemFn) -> syn::Result<Self> {
+		let span = method.sig.span();
+		let name = method.sig.ident.clone();
+		let args = method.sig.inputs.iter().cloned().collect();
+		let return_type = match &method.sig.output {
+			syn::ReturnType::Type(_, ty) => (**ty).clone(),
+			syn::ReturnType::Default => syn::parse_quote!(()),
+		};
+		let docs = get_doc_literals(&method.attrs);
+
+		let mut explicit_id = None;
+		let mut weight = None;
+		for attr in &method.attrs {
+			if attr.path().segments.first().is_some_and(|segment| segment.ident == "pallet") {
+				if let Some(id) = try_parse_view_function_id(attr)? {
+					explicit_id = Some(id);
+				}
+				if let Some(w) = try_parse_view_function_weight(attr)? {
+					weight = Some(w);
+				}
+			}
+		}
+
+		Ok(Self { span, name, args, return_type, docs, explicit_id, weight })
+	}
+
+	/// The identifier of the generated struct that represents a call to this view function.
+	pub fn view_function_struct_ident(&self) -> syn::Ident {
+		quote::format_ident!("ViewFunction{}", pascal_case(&self.name.to_string()))
+	}
+
+	/// The names and types of the view function's arguments, in declaration order.
+	pub fn args_names_types(&self) -> (Vec<syn::Pat>, Vec<syn::Type>) {
+		self.args
+			.iter()
+			.filter_map(|arg| match arg {
+				syn::FnArg::Receiver(_) => None,
+				syn::FnArg::Typed(typed) => Some(((*typed.pat).clone(), (*typed.ty).clone())),
+			})
+			.unzip()
+	}
+
+	/// The id suffix derived from the query's name and argument signature.
+	///
+	/// Used unless overridden by [`Self::explicit_id_suffix_bytes`].
+	pub fn view_function_id_suffix_bytes(&self) -> [u8; 16] {
+		let (_, arg_types) = self.args_names_types();
+		let preimage = arg_types.iter().fold(self.name.to_string(), |mut preimage, ty| {
+			preimage.push('|');
+			preimage.push_str(&quote::quote!(#ty).to_string());
+			preimage
+		});
+		sp_crypto_hashing::twox_128(preimage.as_bytes())
+	}
+
+	/// The id suffix pinned by an explicit `#[pallet::view_function_id(..)]` attribute, if any.
+	pub fn explicit_id_suffix_bytes(&self) -> Option<[u8; 16]> {
+		self.explicit_id
+	}
+
+	/// The weight declared via `#[pallet::view_function(weight = ..)]`, if any.
+	pub fn declared_weight(&self) -> Option<&syn::Expr> {
+		self.weight.as_ref()
+	}
+
+	/// The `E` of a `Result<T, E>` return type, if the query is fallible.
+	pub fn result_error_type(&self) -> Option<syn::Type> {
+		let syn::Type::Path(type_path) = &self.return_type else { return None };
+		let segment = type_path.path.segments.last()?;
+		if segment.ident != "Result" {
+			return None
+		}
+		let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+		match args.args.iter().nth(1)? {
+			syn::GenericArgument::Type(ty) => Some(ty.clone()),
+			_ => None,
+		}
+	}
+
+	/// The `T` of a `Result<T, E>` return type, or the return type itself if the query is
+	/// infallible.
+	///
+	/// This is `ViewFunction::ReturnType`: the value produced by a successful query, with any
+	/// `Result`/[`Self::result_error_type`] wrapping already stripped off.
+	pub fn success_type(&self) -> syn::Type {
+		let syn::Type::Path(type_path) = &self.return_type else { return self.return_type.clone() };
+		let Some(segment) = type_path.path.segments.last() else { return self.return_type.clone() };
+		if segment.ident != "Result" {
+			return self.return_type.clone()
+		}
+		let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+			return self.return_type.clone()
+		};
+		match args.args.iter().next() {
+			Some(syn::GenericArgument::Type(ty)) => ty.clone(),
+			_ => self.return_type.clone(),
+		}
+	}
+}
+
+/// Parses `#[pallet::view_function_id(..)]`, in either of its two forms:
+///
+/// - `#[pallet::view_function_id("stable_name")]`: a stable name, hashed with [`twox_128`] into
+///   the id suffix. Lets the function be renamed, or its arguments reordered, without changing
+///   its on-chain query id.
+/// - `#[pallet::view_function_id(suffix = [..])]`: the 16-byte id suffix itself, spelled out.
+///
+/// [`twox_128`]: sp_crypto_hashing::twox_128
+fn try_parse_view_function_id(attr: &syn::Attribute) -> syn::Result<Option<[u8; 16]>> {
+	let syn::Meta::List(list) = &attr.meta else { return Ok(None) };
+	let Some(ident) = list.path.segments.last().map(|s| &s.ident) else { return Ok(None) };
+	if ident != "view_function_id" {
+		return Ok(None)
+	}
+
+	enum ViewFunctionIdArg {
+		Name(syn::LitStr),
+		Suffix(syn::ExprArray),
+	}
+
+	impl syn::parse::Parse for ViewFunctionIdArg {
+		fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+			if input.peek(syn::LitStr) {
+				return Ok(ViewFunctionIdArg::Name(input.parse()?))
+			}
+
+			let ident: syn::Ident = input.parse()?;
+			if ident != "suffix" {
+				return Err(syn::Error::new(
+					ident.span(),
+					"expected a string literal, e.g. `\"stable_name\"`, or `suffix = [..]`",
+				))
+			}
+			let _: syn::Token![=] = input.parse()?;
+			Ok(ViewFunctionIdArg::Suffix(input.parse()?))
+		}
+	}
+
+	match syn::parse2(list.tokens.clone())? {
+		ViewFunctionIdArg::Name(name) => Ok(Some(sp_crypto_hashing::twox_128(name.value().as_bytes()))),
+		ViewFunctionIdArg::Suffix(array) => {
+			if array.elems.len() != 16 {
+				return Err(syn::Error::new(
+					array.span(),
+					"expected a 16-byte array, e.g. `suffix = [0; 16]`",
+				))
+			}
+			let mut suffix = [0u8; 16];
+			for (byte, elem) in suffix.iter_mut().zip(array.elems.iter()) {
+				let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit_int), .. }) = elem else {
+					return Err(syn::Error::new(elem.span(), "expected a `u8` literal"))
+				};
+				*byte = lit_int.base10_parse()?;
+			}
+			Ok(Some(suffix))
+		},
+	}
+}
+
+/// Parses `#[pallet::view_function(weight = EXPR)]` into the weight expression.
+fn try_parse_view_function_weight(attr: &syn::Attribute) -> syn::Result<Option<syn::Expr>> {
+	let syn::Meta::List(list) = &attr.meta else { return Ok(None) };
+	let Some(ident) = list.path.segments.last().map(|s| &s.ident) else { return Ok(None) };
+	if ident != "view_function" {
+		return Ok(None)
+	}
+
+	struct WeightArg(syn::Expr);
+	impl syn::parse::Parse for WeightArg {
+		fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+			let ident: syn::Ident = input.parse()?;
+			if ident != "weight" {
+				return Err(syn::Error::new(ident.span(), "expected `weight`"))
+			}
+			let _: syn::Token![=] = input.parse()?;
+			Ok(WeightArg(input.parse()?))
+		}
+	}
+
+	Ok(Some(syn::parse2::<WeightArg>(list.tokens.clone())?.0))
+}
+
+/// Extracts the `#[doc = "..."]` string literals attached to an item, in order.
+fn get_doc_literals(attrs: &[syn::Attribute]) -> Vec<syn::Expr> {
+	attrs
+		.iter()
+		.filter_map(|attr| match &attr.meta {
+			syn::Meta::NameValue(meta) if meta.path.is_ident("doc") => Some(meta.value.clone()),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Converts a `snake_case` method name into the `PascalCase` fragment used for the generated
+/// view function struct name, without pulling in the `heck` crate for a single call site.
+fn pascal_case(s: &str) -> String {
+	s.split('_')
+		.map(|word| {
+			let mut chars = word.chars();
+			match chars.next() {
+				Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+				None => String::new(),
+			}
+		})
+		.collect()
+}
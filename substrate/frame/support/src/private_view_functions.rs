@@ -0,0 +1,33 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Re-exports consumed by the code `frame-support-procedural` generates for
+//! `#[pallet::view_functions]`, via `frame_support::__private`.
+//!
+//! Kept in its own module and folded into `__private` by `lib.rs`, the same way every other
+//! macro-facing dependency is re-exported there, so generated code never has to name an
+//! upstream crate directly.
+
+pub use crate::traits::view_functions::{
+	ensure_view_function_weight_limit, ViewFunctionBatchMode, ViewFunctionDispatchError,
+	ViewFunctionId,
+};
+pub use codec;
+pub use scale_info;
+pub use sp_metadata_ir as metadata_ir;
+pub use sp_std;
+pub use sp_weights::Weight;
@@ -0,0 +1,167 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits and types backing the `#[pallet::view_functions]` dispatch machinery generated by
+//! `frame-support-procedural`.
+
+use codec::{Decode, Encode, Output};
+use scale_info::TypeInfo;
+use sp_metadata_ir::ViewFunctionWeightIR;
+use sp_weights::Weight;
+
+impl From<Weight> for ViewFunctionWeightIR {
+	fn from(weight: Weight) -> Self {
+		ViewFunctionWeightIR { ref_time: weight.ref_time(), proof_size: weight.proof_size() }
+	}
+}
+
+/// The id of a view function query: a pallet-level prefix followed by a query-level suffix.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug)]
+pub struct ViewFunctionId {
+	/// Identifies the pallet the query belongs to.
+	pub prefix: [u8; 16],
+	/// Identifies the query within the pallet.
+	pub suffix: [u8; 16],
+}
+
+impl From<ViewFunctionId> for [u8; 32] {
+	fn from(id: ViewFunctionId) -> Self {
+		let mut bytes = [0u8; 32];
+		bytes[..16].copy_from_slice(&id.prefix);
+		bytes[16..].copy_from_slice(&id.suffix);
+		bytes
+	}
+}
+
+/// Implemented by a pallet to identify itself in the [`ViewFunctionId`] of every view function
+/// query it declares.
+pub trait ViewFunctionIdPrefix {
+	/// The prefix shared by every view function query declared by this pallet.
+	fn prefix() -> [u8; 16];
+}
+
+/// Implemented by the struct generated for each view function query to identify itself within
+/// its pallet's [`ViewFunctionIdPrefix`].
+pub trait ViewFunctionIdSuffix {
+	/// The suffix identifying this query within its pallet.
+	const SUFFIX: [u8; 16];
+}
+
+/// A single, dispatchable view function query.
+///
+/// One implementation is generated per method inside a pallet's `#[pallet::view_functions]`
+/// impl block.
+pub trait ViewFunction: Sized + Encode + Decode {
+	/// The value produced by a successful [`Self::invoke`].
+	type ReturnType: Encode + Decode + TypeInfo + 'static;
+
+	/// The error a fallible query can produce.
+	///
+	/// `core::convert::Infallible` for queries that cannot fail.
+	type Error: Encode + TypeInfo + 'static;
+
+	/// The globally unique id of this query.
+	fn id() -> ViewFunctionId;
+
+	/// The weight/PoV cost of serving this query, as declared by
+	/// `#[pallet::view_function(weight = ..)]` (or a default if undeclared).
+	fn weight() -> Weight;
+
+	/// Run the query, returning its declared [`Self::Error`] rather than panicking on a rejected
+	/// query.
+	fn invoke(self) -> Result<Self::ReturnType, Self::Error>;
+
+	/// Decode a query of this type from `input`, run it, and SCALE-encode the outcome to
+	/// `output`: the value on success, or map a rejected query into
+	/// [`ViewFunctionDispatchError::Failed`] carrying the SCALE-encoded [`Self::Error`].
+	///
+	/// Shared by every dispatch entry point so each generated pallet only needs to match on a
+	/// [`ViewFunctionId`] once.
+	fn execute<O: Output>(
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		let query = Self::decode(input).map_err(|_| ViewFunctionDispatchError::DecodingFailed)?;
+		match query.invoke() {
+			Ok(value) => {
+				value.encode_to(output);
+				Ok(())
+			},
+			Err(err) => Err(ViewFunctionDispatchError::Failed(err.encode())),
+		}
+	}
+}
+
+/// Implemented by a pallet to dispatch one of its view function queries by [`ViewFunctionId`].
+///
+/// Generated by `#[pallet::view_functions]`.
+pub trait DispatchViewFunction {
+	/// Dispatch the single query identified by `id`, decoding its arguments from `input` and
+	/// SCALE-encoding its result to `output`.
+	fn dispatch_view_function<O: Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+		weight_limit: Option<Weight>,
+	) -> Result<(), ViewFunctionDispatchError>;
+
+	/// Dispatch a SCALE-encoded batch of `(ViewFunctionId, input)` queries in one call,
+	/// appending each result (or per-item error, depending on `mode`) to `output`.
+	fn dispatch_view_functions<O: Output>(
+		queries: &mut &[u8],
+		output: &mut O,
+		weight_limit: Option<Weight>,
+		mode: ViewFunctionBatchMode,
+	) -> Result<(), ViewFunctionDispatchError>;
+}
+
+/// Controls how [`DispatchViewFunction::dispatch_view_functions`] handles a failing query within
+/// a batch.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug)]
+pub enum ViewFunctionBatchMode {
+	/// Stop at, and return, the first error encountered.
+	ShortCircuit,
+	/// Encode the error in place of that query's result and continue with the rest of the
+	/// batch.
+	Collect,
+}
+
+/// The error produced when dispatching a view function query fails.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug)]
+pub enum ViewFunctionDispatchError {
+	/// No query with this id is known to the runtime.
+	NotFound(ViewFunctionId),
+	/// The query's arguments failed to decode from the supplied input.
+	DecodingFailed,
+	/// The query's declared weight exceeds the caller-supplied `weight_limit`.
+	WeightLimitExceeded,
+	/// The query ran and returned its own (SCALE-encoded) [`ViewFunction::Error`] value.
+	Failed(sp_std::vec::Vec<u8>),
+}
+
+/// Returns [`ViewFunctionDispatchError::WeightLimitExceeded`] if `V`'s declared weight does not
+/// fit within `weight_limit`.
+///
+/// A `None` limit means the caller has not imposed one.
+pub fn ensure_view_function_weight_limit<V: ViewFunction>(
+	weight_limit: Option<Weight>,
+) -> Result<(), ViewFunctionDispatchError> {
+	match weight_limit {
+		Some(limit) if !V::weight().all_lte(limit) => Err(ViewFunctionDispatchError::WeightLimitExceeded),
+		_ => Ok(()),
+	}
+}
@@ -22,6 +22,67 @@ use scale_info::{
 	IntoPortable, Registry,
 };
 
+/// Serializes and deserializes `[u8]` types to and from hex, using the `serde` feature.
+///
+/// Byte blobs such as SCALE-encoded values or view function IDs are otherwise dumped as a JSON
+/// array of numbers, which is not human-readable.
+#[cfg(feature = "serde")]
+mod bytes_hex {
+	use super::Vec;
+	use scale_info::prelude::{format, string::String};
+	use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+	pub fn serialize<S: Serializer, T: AsRef<[u8]>>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error> {
+		format!("0x{}", impl_hex::encode(bytes.as_ref())).serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		impl_hex::decode(s.trim_start_matches("0x")).map_err(serde::de::Error::custom)
+	}
+
+	/// A tiny hex codec so this module doesn't need an extra dependency just for `serde`.
+	pub(super) mod impl_hex {
+		use super::{format, String};
+		use scale_info::prelude::vec::Vec;
+
+		pub fn encode(bytes: &[u8]) -> String {
+			bytes.iter().map(|b| format!("{:02x}", b)).collect()
+		}
+
+		pub fn decode(s: &str) -> Result<Vec<u8>, String> {
+			if s.len() % 2 != 0 {
+				return Err("hex string has an odd length".into())
+			}
+			(0..s.len())
+				.step_by(2)
+				.map(|i| {
+					u8::from_str_radix(&s[i..i + 2], 16)
+						.map_err(|_| format!("invalid hex byte at offset {i}"))
+				})
+				.collect()
+		}
+	}
+}
+
+/// Like [`bytes_hex`], but for the fixed-size `[u8; 32]` view function id.
+#[cfg(feature = "serde")]
+mod id_hex {
+	use super::bytes_hex::impl_hex;
+	use scale_info::prelude::string::String;
+	use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+	pub fn serialize<S: Serializer>(id: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error> {
+		scale_info::prelude::format!("0x{}", impl_hex::encode(id)).serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+		let s = String::deserialize(deserializer)?;
+		let bytes = impl_hex::decode(s.trim_start_matches("0x")).map_err(serde::de::Error::custom)?;
+		bytes.try_into().map_err(|_| serde::de::Error::custom("expected a 32-byte view function id"))
+	}
+}
+
 /// The intermediate representation for the runtime metadata.
 /// Contains the needed context that allows conversion to multiple metadata versions.
 ///
@@ -30,6 +91,14 @@ use scale_info::{
 /// Further fields could be added or removed to ensure proper conversion.
 /// When the IR does not contain enough information to generate a specific version
 /// of the runtime metadata an appropriate default value is used (ie, empty vector).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct MetadataIR<T: Form = MetaForm> {
 	/// Pallet metadata.
 	pub pallets: Vec<PalletMetadataIR<T>>,
@@ -43,10 +112,84 @@ pub struct MetadataIR<T: Form = MetaForm> {
 	pub outer_enums: OuterEnumsIR<T>,
 	/// Metadata of view function queries
 	pub view_functions: RuntimeViewFunctionsIR<T>,
+	/// Chain-specific metadata that doesn't fit any of the other categories.
+	pub custom: CustomMetadataIR<T>,
+}
+
+/// Custom metadata of a runtime, for chain-specific data.
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
+pub struct CustomMetadataIR<T: Form = MetaForm> {
+	/// A map of all the custom types and values.
+	pub map: BTreeMap<T::String, CustomValueMetadataIR<T>>,
+}
+
+impl<T: Form> Default for CustomMetadataIR<T> {
+	/// An empty custom metadata section.
+	///
+	/// Chains that don't register any custom values can build a [`MetadataIR`] with
+	/// `custom: CustomMetadataIR::default()`.
+	fn default() -> Self {
+		CustomMetadataIR { map: BTreeMap::new() }
+	}
+}
+
+impl IntoPortable for CustomMetadataIR {
+	type Output = CustomMetadataIR<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		let map = self
+			.map
+			.into_iter()
+			.map(|(key, value)| (key.into_portable(registry), value.into_portable(registry)))
+			.collect();
+		CustomMetadataIR { map }
+	}
+}
+
+/// A custom value of the custom metadata, specific to a certain chain.
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
+pub struct CustomValueMetadataIR<T: Form = MetaForm> {
+	/// The type of the value.
+	pub ty: T::Type,
+	/// The SCALE encoded value, to be decoded using the type `ty`.
+	#[cfg_attr(feature = "serde", serde(with = "bytes_hex"))]
+	pub value: Vec<u8>,
+}
+
+impl IntoPortable for CustomValueMetadataIR {
+	type Output = CustomValueMetadataIR<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		CustomValueMetadataIR { ty: registry.register_type(&self.ty), value: self.value }
+	}
 }
 
 /// Metadata of a runtime trait.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct RuntimeApiMetadataIR<T: Form = MetaForm> {
 	/// Trait name.
 	pub name: T::String,
@@ -56,6 +199,22 @@ pub struct RuntimeApiMetadataIR<T: Form = MetaForm> {
 	pub docs: Vec<T::String>,
 	/// Deprecation info
 	pub deprecation_info: DeprecationStatusIR<T>,
+	/// The version of the runtime API trait that the runtime implements.
+	pub version: Compact<u32>,
+}
+
+impl<T: Form> RuntimeApiMetadataIR<T> {
+	/// Build a [`RuntimeApiMetadataIR`] for a runtime API trait that hasn't declared an explicit
+	/// version, defaulting [`Self::version`] to `1` (the implicit version of every runtime API
+	/// trait before versioning was introduced).
+	pub fn with_default_version(
+		name: T::String,
+		methods: Vec<RuntimeApiMethodMetadataIR<T>>,
+		docs: Vec<T::String>,
+		deprecation_info: DeprecationStatusIR<T>,
+	) -> Self {
+		Self { name, methods, docs, deprecation_info, version: Compact(1) }
+	}
 }
 
 impl IntoPortable for RuntimeApiMetadataIR {
@@ -67,12 +226,21 @@ impl IntoPortable for RuntimeApiMetadataIR {
 			methods: registry.map_into_portable(self.methods),
 			docs: registry.map_into_portable(self.docs),
 			deprecation_info: self.deprecation_info.into_portable(registry),
+			version: self.version,
 		}
 	}
 }
 
 /// Metadata of a runtime method.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct RuntimeApiMethodMetadataIR<T: Form = MetaForm> {
 	/// Method name.
 	pub name: T::String,
@@ -102,11 +270,30 @@ impl IntoPortable for RuntimeApiMethodMetadataIR {
 
 /// Metadata of a runtime method parameter.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct RuntimeApiMethodParamMetadataIR<T: Form = MetaForm> {
 	/// Parameter name.
 	pub name: T::String,
 	/// Parameter type.
 	pub ty: T::Type,
+	/// Deprecation info
+	pub deprecation_info: DeprecationStatusIR<T>,
+}
+
+impl<T: Form> RuntimeApiMethodParamMetadataIR<T> {
+	/// Build a [`RuntimeApiMethodParamMetadataIR`] for a parameter that doesn't carry its own
+	/// `#[deprecated]` attribute, defaulting [`Self::deprecation_info`] to
+	/// [`DeprecationStatusIR::NotDeprecated`].
+	pub fn not_deprecated(name: T::String, ty: T::Type) -> Self {
+		Self { name, ty, deprecation_info: DeprecationStatusIR::NotDeprecated }
+	}
 }
 
 impl IntoPortable for RuntimeApiMethodParamMetadataIR {
@@ -115,6 +302,7 @@ impl IntoPortable for RuntimeApiMethodParamMetadataIR {
 	fn into_portable(self, registry: &mut Registry) -> Self::Output {
 		RuntimeApiMethodParamMetadataIR {
 			name: self.name.into_portable(registry),
+			deprecation_info: self.deprecation_info.into_portable(registry),
 			ty: registry.register_type(&self.ty),
 		}
 	}
@@ -122,6 +310,14 @@ impl IntoPortable for RuntimeApiMethodParamMetadataIR {
 
 /// Metadata of the the runtime query dispatch.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct RuntimeViewFunctionsIR<T: Form = MetaForm> {
 	/// The type implementing the runtime query dispatch.
 	pub ty: T::Type,
@@ -131,6 +327,14 @@ pub struct RuntimeViewFunctionsIR<T: Form = MetaForm> {
 
 /// Metadata of a runtime query interface.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct ViewFunctionsInterfaceIR<T: Form = MetaForm> {
 	/// Name of the query interface.
 	pub name: T::String,
@@ -138,33 +342,89 @@ pub struct ViewFunctionsInterfaceIR<T: Form = MetaForm> {
 	pub queries: Vec<ViewFunctionMetadataIR<T>>,
 	/// Query interface documentation.
 	pub docs: Vec<T::String>,
+	/// Deprecation info of the interface.
+	///
+	/// A [`DeprecationStatusIR::Deprecated`] here applies to every query in `queries` that does
+	/// not carry its own [`ViewFunctionMetadataIR::deprecation_info`]; a query-level status always
+	/// overrides the interface-level one.
+	pub deprecation_info: DeprecationStatusIR<T>,
+}
+
+/// Resolves the effective deprecation status of a view function query, applying the
+/// precedence documented on [`ViewFunctionsInterfaceIR::deprecation_info`]: a query-level
+/// status always overrides the interface-level one, which otherwise applies to every query in
+/// the interface.
+fn effective_view_function_deprecation<T: Form>(
+	interface_deprecation: &DeprecationStatusIR<T>,
+	query_deprecation: DeprecationStatusIR<T>,
+) -> DeprecationStatusIR<T> {
+	match query_deprecation {
+		DeprecationStatusIR::NotDeprecated => interface_deprecation.clone(),
+		overridden => overridden,
+	}
 }
 
 impl IntoPortable for ViewFunctionsInterfaceIR {
 	type Output = ViewFunctionsInterfaceIR<PortableForm>;
 
 	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		let interface_deprecation = self.deprecation_info;
+		let queries = self
+			.queries
+			.into_iter()
+			.map(|mut query| {
+				query.deprecation_info =
+					effective_view_function_deprecation(&interface_deprecation, query.deprecation_info);
+				query.into_portable(registry)
+			})
+			.collect();
 		ViewFunctionsInterfaceIR {
 			name: self.name.into_portable(registry),
-			queries: registry.map_into_portable(self.queries),
+			queries,
 			docs: registry.map_into_portable(self.docs),
+			deprecation_info: interface_deprecation.into_portable(registry),
 		}
 	}
 }
 
 /// Metadata of a runtime view function.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct ViewFunctionMetadataIR<T: Form = MetaForm> {
 	/// Query name.
 	pub name: T::String,
 	/// Query id.
+	#[cfg_attr(feature = "serde", serde(with = "id_hex"))]
 	pub id: [u8; 32],
+	/// Whether `id` was pinned by an explicit `#[pallet::view_function_id(..)]` attribute, rather
+	/// than derived from the query's name and argument signature.
+	///
+	/// Tooling can use this to warn when an un-pinned id is at risk of accidental churn from an
+	/// innocuous rename.
+	pub id_is_explicit: bool,
 	/// Query args.
 	pub args: Vec<QueryArgMetadataIR<T>>,
 	/// Query output.
 	pub output: T::Type,
 	/// Query documentation.
 	pub docs: Vec<T::String>,
+	/// The weight/PoV cost declared for serving this query.
+	pub weight: ViewFunctionWeightIR,
+	/// The query's associated error type.
+	///
+	/// For an infallible query this is `core::convert::Infallible`.
+	pub error: T::Type,
+	/// Deprecation info of the query.
+	///
+	/// Overrides the owning [`ViewFunctionsInterfaceIR::deprecation_info`], if any.
+	pub deprecation_info: DeprecationStatusIR<T>,
 }
 
 impl IntoPortable for ViewFunctionMetadataIR {
@@ -174,15 +434,40 @@ impl IntoPortable for ViewFunctionMetadataIR {
 		ViewFunctionMetadataIR {
 			name: self.name.into_portable(registry),
 			id: self.id,
+			id_is_explicit: self.id_is_explicit,
 			args: registry.map_into_portable(self.args),
 			output: registry.register_type(&self.output),
 			docs: registry.map_into_portable(self.docs),
+			weight: self.weight,
+			error: registry.register_type(&self.error),
+			deprecation_info: self.deprecation_info.into_portable(registry),
 		}
 	}
 }
 
+/// The weight/PoV cost declared for a view function query.
+///
+/// Unlike most metadata IR fields this isn't generic over [`Form`]: it is plain data, not a type
+/// reference that needs registry interning.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewFunctionWeightIR {
+	/// Computational time used, in picoseconds.
+	pub ref_time: u64,
+	/// Proof-of-validity size used, in bytes.
+	pub proof_size: u64,
+}
+
 /// Metadata of a runtime method argument.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct QueryArgMetadataIR<T: Form = MetaForm> {
 	/// Query argument name.
 	pub name: T::String,
@@ -201,8 +486,53 @@ impl IntoPortable for QueryArgMetadataIR {
 	}
 }
 
+/// Metadata of a pallet's view functions, as collected by the `#[pallet::view_functions]` macro.
+///
+/// A runtime aggregates one of these per pallet into a [`ViewFunctionsInterfaceIR`].
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
+pub struct ViewFunctionGroupIR<T: Form = MetaForm> {
+	/// Name of the pallet the queries belong to.
+	pub name: T::String,
+	/// Queries belonging to the pallet.
+	pub view_functions: Vec<ViewFunctionMetadataIR<T>>,
+	/// Pallet documentation.
+	pub docs: Vec<T::String>,
+	/// Whether the pallet's generated dispatcher supports batching multiple queries into a
+	/// single call, via `DispatchViewFunction::dispatch_view_functions`.
+	pub supports_batch_dispatch: bool,
+}
+
+impl IntoPortable for ViewFunctionGroupIR {
+	type Output = ViewFunctionGroupIR<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		ViewFunctionGroupIR {
+			name: self.name.into_portable(registry),
+			view_functions: registry.map_into_portable(self.view_functions),
+			docs: registry.map_into_portable(self.docs),
+			supports_batch_dispatch: self.supports_batch_dispatch,
+		}
+	}
+}
+
 /// The intermediate representation for a pallet metadata.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct PalletMetadataIR<T: Form = MetaForm> {
 	/// Pallet name.
 	pub name: T::String,
@@ -248,12 +578,22 @@ impl IntoPortable for PalletMetadataIR {
 
 /// Metadata of the extrinsic used by the runtime.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct ExtrinsicMetadataIR<T: Form = MetaForm> {
 	/// The type of the extrinsic.
 	///
 	/// Note: Field used for metadata V14 only.
 	pub ty: T::Type,
 	/// Extrinsic version.
+	///
+	/// Note: Kept for backward-compatible V14/V15 conversion; set to the maximum of `versions`.
 	pub version: u8,
 	/// The type of the address that signs the extrinsic
 	pub address_ty: T::Type,
@@ -266,6 +606,43 @@ pub struct ExtrinsicMetadataIR<T: Form = MetaForm> {
 	pub extra_ty: T::Type,
 	/// The transaction extensions in the order they appear in the extrinsic.
 	pub extensions: Vec<TransactionExtensionMetadataIR<T>>,
+	/// All the extrinsic format versions supported by the runtime.
+	pub versions: Vec<u8>,
+	/// For each supported extrinsic format version, the indices into `extensions` of the
+	/// transaction extensions that apply to that version.
+	pub extensions_by_version: BTreeMap<u8, Vec<Compact<u32>>>,
+}
+
+impl<T: Form> ExtrinsicMetadataIR<T> {
+	/// Derive the V14-compatible [`Self::version`] from `versions`.
+	///
+	/// `version` becomes the maximum of `versions` (the latest format a V14/V15 consumer, which
+	/// only understands a single version, should assume). `extensions` and
+	/// `extensions_by_version` are stored as given, so the indices in `extensions_by_version`
+	/// keep pointing into `extensions`.
+	pub fn with_latest_version_fields(
+		ty: T::Type,
+		address_ty: T::Type,
+		call_ty: T::Type,
+		signature_ty: T::Type,
+		extra_ty: T::Type,
+		extensions: Vec<TransactionExtensionMetadataIR<T>>,
+		versions: Vec<u8>,
+		extensions_by_version: BTreeMap<u8, Vec<Compact<u32>>>,
+	) -> Self {
+		let version = versions.iter().copied().max().unwrap_or_default();
+		Self {
+			ty,
+			version,
+			address_ty,
+			call_ty,
+			signature_ty,
+			extra_ty,
+			extensions,
+			versions,
+			extensions_by_version,
+		}
+	}
 }
 
 impl IntoPortable for ExtrinsicMetadataIR {
@@ -280,12 +657,22 @@ impl IntoPortable for ExtrinsicMetadataIR {
 			signature_ty: registry.register_type(&self.signature_ty),
 			extra_ty: registry.register_type(&self.extra_ty),
 			extensions: registry.map_into_portable(self.extensions),
+			versions: self.versions,
+			extensions_by_version: self.extensions_by_version,
 		}
 	}
 }
 
 /// Metadata of a pallet's associated type.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct PalletAssociatedTypeMetadataIR<T: Form = MetaForm> {
 	/// The name of the associated type.
 	pub name: T::String,
@@ -309,6 +696,14 @@ impl IntoPortable for PalletAssociatedTypeMetadataIR {
 
 /// Metadata of an extrinsic's signed extension.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct TransactionExtensionMetadataIR<T: Form = MetaForm> {
 	/// The unique signed extension identifier, which may be different from the type name.
 	pub identifier: T::String,
@@ -316,6 +711,8 @@ pub struct TransactionExtensionMetadataIR<T: Form = MetaForm> {
 	pub ty: T::Type,
 	/// The type of the implicit data, with the data to be included in the signed payload.
 	pub implicit: T::Type,
+	/// The extrinsic format version this transaction extension applies to.
+	pub version: u8,
 }
 
 impl IntoPortable for TransactionExtensionMetadataIR {
@@ -326,12 +723,14 @@ impl IntoPortable for TransactionExtensionMetadataIR {
 			identifier: self.identifier.into_portable(registry),
 			ty: registry.register_type(&self.ty),
 			implicit: registry.register_type(&self.implicit),
+			version: self.version,
 		}
 	}
 }
 
 /// All metadata of the pallet's storage.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The common prefix used by all storage entries.
 pub struct PalletStorageMetadataIR<T: Form = MetaForm> {
 	/// The common prefix used by all storage entries.
@@ -353,6 +752,14 @@ impl IntoPortable for PalletStorageMetadataIR {
 
 /// Metadata about one storage entry.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct StorageEntryMetadataIR<T: Form = MetaForm> {
 	/// Variable name of the storage entry.
 	pub name: T::String,
@@ -361,6 +768,7 @@ pub struct StorageEntryMetadataIR<T: Form = MetaForm> {
 	/// Type of the value stored in the entry.
 	pub ty: StorageEntryTypeIR<T>,
 	/// Default value (SCALE encoded).
+	#[cfg_attr(feature = "serde", serde(with = "bytes_hex"))]
 	pub default: Vec<u8>,
 	/// Storage entry documentation.
 	pub docs: Vec<T::String>,
@@ -391,6 +799,7 @@ impl IntoPortable for StorageEntryMetadataIR {
 /// present. `Default` means you should expect a `T` with the default value of default if the key is
 /// not present.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StorageEntryModifierIR {
 	/// The storage entry returns an `Option<T>`, with `None` if the key is not present.
 	Optional,
@@ -400,6 +809,7 @@ pub enum StorageEntryModifierIR {
 
 /// Hasher used by storage maps
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StorageHasherIR {
 	/// 128-bit Blake2 hash.
 	Blake2_128,
@@ -419,6 +829,14 @@ pub enum StorageHasherIR {
 
 /// A type of storage value.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub enum StorageEntryTypeIR<T: Form = MetaForm> {
 	/// Plain storage entry (just the value).
 	Plain(T::Type),
@@ -450,6 +868,14 @@ impl IntoPortable for StorageEntryTypeIR {
 
 /// Metadata for all calls in a pallet
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct PalletCallMetadataIR<T: Form = MetaForm> {
 	/// The corresponding enum type for the pallet call.
 	pub ty: T::Type,
@@ -470,6 +896,14 @@ impl IntoPortable for PalletCallMetadataIR {
 
 /// Metadata about the pallet Event type.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct PalletEventMetadataIR<T: Form = MetaForm> {
 	/// The Event type.
 	pub ty: T::Type,
@@ -490,12 +924,21 @@ impl IntoPortable for PalletEventMetadataIR {
 
 /// Metadata about one pallet constant.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct PalletConstantMetadataIR<T: Form = MetaForm> {
 	/// Name of the pallet constant.
 	pub name: T::String,
 	/// Type of the pallet constant.
 	pub ty: T::Type,
 	/// Value stored in the constant (SCALE encoded).
+	#[cfg_attr(feature = "serde", serde(with = "bytes_hex"))]
 	pub value: Vec<u8>,
 	/// Documentation of the constant.
 	pub docs: Vec<T::String>,
@@ -519,6 +962,14 @@ impl IntoPortable for PalletConstantMetadataIR {
 
 /// Metadata about a pallet error.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct PalletErrorMetadataIR<T: Form = MetaForm> {
 	/// The error type information.
 	pub ty: T::Type,
@@ -539,6 +990,14 @@ impl IntoPortable for PalletErrorMetadataIR {
 
 /// The type of the outer enums.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub struct OuterEnumsIR<T: Form = MetaForm> {
 	/// The type of the outer `RuntimeCall` enum.
 	pub call_enum_ty: T::Type,
@@ -576,6 +1035,14 @@ impl IntoPortable for OuterEnumsIR {
 
 /// Deprecation status for an entry inside MetadataIR
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub enum DeprecationStatusIR<T: Form = MetaForm> {
 	/// Entry is not deprecated
 	NotDeprecated,
@@ -607,6 +1074,14 @@ impl IntoPortable for DeprecationStatusIR {
 /// Deprecation info for an enums/errors/calls.
 /// Denotes full/partial deprecation of the type
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T::Type: serde::Serialize, T::String: serde::Serialize",
+		deserialize = "T::Type: serde::de::DeserializeOwned, T::String: serde::de::DeserializeOwned"
+	))
+)]
 pub enum DeprecationInfoIR<T: Form = MetaForm> {
 	/// Type is not deprecated
 	NotDeprecated,